@@ -250,3 +250,9 @@ impl PwmAsync {
         Ok(())
     }
 }
+
+// `PwmAsync` has no `embedded-hal` counterpart: `embedded-hal-async` only
+// defines `delay`, `digital`, `i2c`, and `spi` traits and has no `pwm`
+// module (duty-cycle setting is a synchronous register/sysfs write, so
+// the ecosystem never standardized an async PWM trait). See `Pwm`'s
+// `embedded_hal::pwm::SetDutyCycle` impl in `lib.rs` for the sync side.
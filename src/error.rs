@@ -22,14 +22,7 @@ pub enum Error {
 }
 
 impl ::std::error::Error for Error {
-    fn description(&self) -> &str {
-        match *self {
-            Error::Io(ref e) => e.description(),
-            Error::Unexpected(_) => "something unexpected",
-        }
-    }
-
-    fn cause(&self) -> Option<&dyn (::std::error::Error)> {
+    fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
         match *self {
             Error::Io(ref e) => Some(e),
             _ => None,
@@ -51,3 +44,10 @@ impl convert::From<io::Error> for Error {
         Error::Io(e)
     }
 }
+
+#[cfg(feature = "embedded-hal")]
+impl embedded_hal::pwm::Error for Error {
+    fn kind(&self) -> embedded_hal::pwm::ErrorKind {
+        embedded_hal::pwm::ErrorKind::Other
+    }
+}
@@ -29,6 +29,13 @@ pub struct PwmChip {
 pub struct Pwm {
     chip: PwmChip,
     number: u32,
+    /// The period in nanoseconds (clamped to `u16::MAX`) reported by
+    /// `SetDutyCycle::max_duty_cycle`, captured the first time that method
+    /// successfully reads a non-zero value and then held fixed. `0` means it
+    /// hasn't been captured yet, either because `max_duty_cycle` hasn't been
+    /// called or because every read so far has failed or returned `0`.
+    #[cfg(feature = "embedded-hal")]
+    max_duty_cycle_cache: std::sync::atomic::AtomicU16,
 }
 
 #[derive(Debug)]
@@ -143,7 +150,12 @@ impl Pwm {
     /// This function does not export the Pwm pin
     pub fn new(chip: u32, number: u32) -> Result<Pwm> {
         let chip: PwmChip = PwmChip::new(chip)?;
-        Ok(Pwm { chip, number })
+        Ok(Pwm {
+            chip,
+            number,
+            #[cfg(feature = "embedded-hal")]
+            max_duty_cycle_cache: std::sync::atomic::AtomicU16::new(0),
+        })
     }
 
     /// Run a closure with the GPIO exported
@@ -269,3 +281,46 @@ impl Pwm {
         }
     }
 }
+
+#[cfg(feature = "embedded-hal")]
+impl embedded_hal::pwm::ErrorType for Pwm {
+    type Error = Error;
+}
+
+#[cfg(feature = "embedded-hal")]
+impl embedded_hal::pwm::SetDutyCycle for Pwm {
+    /// Resolution of `set_duty_cycle`: the period in nanoseconds, clamped
+    /// to `u16::MAX`, captured the first time this method reads a non-zero
+    /// value and then held fixed for the lifetime of this `Pwm`, so a
+    /// driver that reads it once keeps a valid `max` even if the period
+    /// is later changed (e.g. by another process).
+    ///
+    /// `max_duty_cycle` cannot return an error, so a read failure (or not
+    /// having captured a period yet) is reported as a resolution of `0`;
+    /// callers that need to detect that should call `get_period_ns`
+    /// directly rather than relying on this trait.
+    fn max_duty_cycle(&self) -> u16 {
+        use std::sync::atomic::Ordering;
+        let cached = self.max_duty_cycle_cache.load(Ordering::Relaxed);
+        if cached != 0 {
+            return cached;
+        }
+        let max = self
+            .get_period_ns()
+            .unwrap_or(0)
+            .min(u32::from(u16::MAX)) as u16;
+        self.max_duty_cycle_cache.store(max, Ordering::Relaxed);
+        max
+    }
+
+    /// Writes `duty / max_duty_cycle()` of the current period as the duty
+    /// cycle in nanoseconds. While the period is unchanged and no larger
+    /// than `u16::MAX`, this is `duty` nanoseconds; a resolution of `0`
+    /// writes a duty cycle of `0`.
+    fn set_duty_cycle(&mut self, duty: u16) -> ::std::result::Result<(), Self::Error> {
+        let period = u64::from(self.get_period_ns()?);
+        let max = u64::from(self.max_duty_cycle());
+        let duty_ns = (u64::from(duty) * period).checked_div(max).unwrap_or(0);
+        self.set_duty_cycle_ns(duty_ns.min(u64::from(u32::MAX)) as u32)
+    }
+}